@@ -0,0 +1,125 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::error::Error;
+use crate::retry::RetryConfig;
+use crate::ProgressCallback;
+use futures_util::StreamExt;
+use sha2::{Digest, Sha256};
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+
+/// Downloads `url` into `archive_path`, resuming a partial download left over from a previous
+/// attempt and retrying transient failures with exponential backoff. Returns the hasher over the
+/// full file contents so the caller can verify it against an expected checksum.
+pub(crate) async fn download_with_resume(
+    client: &reqwest::Client,
+    url: &str,
+    archive_path: &Path,
+    progress_callback: &ProgressCallback,
+    retry_config: &RetryConfig,
+) -> Result<Sha256, Error> {
+    let mut hasher = Sha256::new();
+    let mut downloaded: u64 = 0;
+
+    // A partial file can already be sitting in `download_dir` from a process that was
+    // interrupted before this call was ever made, so the hasher needs to be primed with those
+    // bytes up front; `attempt_download` only carries `hasher` across retries *within* this call,
+    // it never sees bytes written in an earlier process run.
+    if let Ok(mut existing) = File::open(archive_path) {
+        let mut buf = [0u8; 64 * 1024];
+        loop {
+            let n = std::io::Read::read(&mut existing, &mut buf)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+            downloaded += n as u64;
+        }
+    }
+
+    let mut attempt = 0;
+
+    loop {
+        match attempt_download(client, url, archive_path, &mut hasher, &mut downloaded, progress_callback).await {
+            Ok(()) => return Ok(hasher),
+            Err(e) if is_transient(&e) => {
+                attempt += 1;
+                if attempt >= retry_config.max_attempts {
+                    return Err(Error::DownloadRetriesExhausted {
+                        attempts: retry_config.max_attempts,
+                    });
+                }
+                let delay = retry_config.backoff_delay(attempt);
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// A single download attempt. `hasher` and `downloaded` persist across retries so that bytes
+/// already written in a prior attempt aren't re-hashed or double-counted in the progress
+/// callback; they're only reset if the server doesn't honour a range request.
+async fn attempt_download(
+    client: &reqwest::Client,
+    url: &str,
+    archive_path: &Path,
+    hasher: &mut Sha256,
+    downloaded: &mut u64,
+    progress_callback: &ProgressCallback,
+) -> Result<(), Error> {
+    let existing_len = std::fs::metadata(archive_path).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(url);
+    if existing_len > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={existing_len}-"));
+    }
+    let response = request.send().await?;
+    let status = response.status();
+
+    if status == reqwest::StatusCode::PARTIAL_CONTENT && existing_len > 0 {
+        *downloaded = existing_len;
+    } else if status.is_success() {
+        // Either this is the first attempt, or the server ignored our range request and sent
+        // the full file back (200); either way we start the archive over from scratch.
+        *downloaded = 0;
+        *hasher = Sha256::new();
+    } else if status.is_server_error() {
+        return Err(Error::ServerError(status));
+    } else {
+        return Err(Error::ReleaseBinaryNotFound(url.to_string()));
+    }
+
+    let mut file = if *downloaded > 0 {
+        OpenOptions::new().append(true).open(archive_path)?
+    } else {
+        File::create(archive_path)?
+    };
+
+    let total = response.content_length().unwrap_or(0) + *downloaded;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        std::io::Write::write_all(&mut file, &chunk)?;
+        hasher.update(&chunk);
+        *downloaded += chunk.len() as u64;
+        progress_callback(*downloaded, total);
+    }
+    Ok(())
+}
+
+fn is_transient(error: &Error) -> bool {
+    match error {
+        Error::ServerError(_) => true,
+        Error::Reqwest(e) => {
+            e.is_connect() || e.is_timeout() || e.is_body() || e.status().is_some_and(|s| s.is_server_error())
+        }
+        _ => false,
+    }
+}
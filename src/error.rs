@@ -0,0 +1,29 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum Error {
+    #[error("Could not find the binary at {0}")]
+    ReleaseBinaryNotFound(String),
+    #[error("Checksum verification failed: expected {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+    #[error("Could not determine a supported platform for this host: {0}")]
+    UnsupportedPlatform(String),
+    #[error("Invalid version request: {0}")]
+    InvalidVersionRequest(String),
+    #[error("An IO error occurred: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("An error occurred while making an HTTP request: {0}")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("The server responded with a {0} error")]
+    ServerError(reqwest::StatusCode),
+    #[error("Gave up downloading after {attempts} attempts")]
+    DownloadRetriesExhausted { attempts: u32 },
+}
@@ -0,0 +1,269 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::error::Error;
+use crate::retry::RetryConfig;
+use crate::{
+    download, extract_archive, version, ArchiveType, Platform, ProgressCallback, ReleaseManifest,
+    ReleaseType, SafeReleaseRepositoryInterface,
+};
+use async_trait::async_trait;
+use semver::Version;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const GITHUB_API_BASE_URL: &str = "https://api.github.com";
+
+#[derive(Debug, Deserialize)]
+struct GithubTag {
+    name: String,
+}
+
+/// Downloads SAFE Network binaries from GitHub Releases rather than the S3 bucket, as a fallback
+/// for when S3 is unavailable, or to consume a community mirror repository.
+///
+/// Release archives on GitHub don't carry the version in their filename the way the S3 ones do
+/// (the version lives in the release tag instead), so assets are expected to follow the
+/// `<release-type>-<arch-triple>.<ext>` naming convention, e.g. `safe-x86_64-apple-darwin.tar.gz`.
+pub struct GithubReleaseRepository {
+    /// Maps a release type's display name (e.g. `"safenode-manager"`) to the `(owner, repo)` it's
+    /// published from.
+    repos: HashMap<String, (String, String)>,
+    /// Reused to work out the extracted binary name, the one part of the download that doesn't
+    /// depend on which host is serving the archive.
+    manifest: ReleaseManifest,
+    retry_config: RetryConfig,
+}
+
+impl GithubReleaseRepository {
+    /// Builds a repository using this crate's default mapping of each SAFE binary to its
+    /// `maidsafe` GitHub repository.
+    pub fn default_config() -> Box<dyn SafeReleaseRepositoryInterface> {
+        Box::new(Self {
+            repos: default_repo_mapping(),
+            manifest: ReleaseManifest::default_manifest(),
+            retry_config: RetryConfig::default(),
+        })
+    }
+
+    /// Builds a repository from a caller-supplied mapping of release type name to `(owner, repo)`,
+    /// for pointing at a community mirror or a fork.
+    pub fn from_repo_mapping(repos: HashMap<String, (String, String)>) -> Box<dyn SafeReleaseRepositoryInterface> {
+        Box::new(Self {
+            repos,
+            manifest: ReleaseManifest::default_manifest(),
+            retry_config: RetryConfig::default(),
+        })
+    }
+
+    fn repo(&self, release_type: &ReleaseType) -> Result<&(String, String), Error> {
+        self.repos
+            .get(&release_type.to_string())
+            .ok_or_else(|| Error::ReleaseBinaryNotFound(release_type.to_string()))
+    }
+
+    fn asset_filename(release_type: &ReleaseType, platform: &Platform, archive_type: &ArchiveType) -> String {
+        format!(
+            "{release_type}-{arch_triple}.{ext}",
+            arch_triple = platform.get_arch_triple(),
+            ext = archive_type.extension()
+        )
+    }
+
+    /// The name `asset_filename` is saved under in `download_dir`. Unlike the remote asset,
+    /// which GitHub requires to be named identically across releases, this is namespaced by
+    /// `tag` so that resuming a download never mistakes a different version's leftover partial
+    /// file for one of the currently requested version. It still starts with `release_type`'s
+    /// display name so [`crate::ReleaseManifest::entry_for_archive`] can map it back during
+    /// extraction.
+    fn local_archive_filename(release_type: &ReleaseType, tag: &str, platform: &Platform, archive_type: &ArchiveType) -> String {
+        format!(
+            "{release_type}-{tag}-{arch_triple}.{ext}",
+            arch_triple = platform.get_arch_triple(),
+            ext = archive_type.extension()
+        )
+    }
+
+    async fn resolve_tag(client: &reqwest::Client, owner: &str, repo: &str, version_req: &str) -> Result<String, Error> {
+        let tags = fetch_tags(client, owner, repo).await?;
+        let parsed: Vec<(Version, String)> = tags
+            .into_iter()
+            .filter_map(|tag| {
+                let version = Version::parse(tag.name.strip_prefix('v').unwrap_or(&tag.name)).ok()?;
+                Some((version, tag.name))
+            })
+            .collect();
+        let versions: Vec<Version> = parsed.iter().map(|(v, _)| v.clone()).collect();
+        let resolved = version::resolve(version_req, &versions)?;
+        parsed
+            .into_iter()
+            .find(|(v, _)| v.to_string() == resolved)
+            .map(|(_, tag)| tag)
+            .ok_or_else(|| Error::ReleaseBinaryNotFound(version_req.to_string()))
+    }
+}
+
+/// Strips a caller-supplied `v` prefix so an exact version request resolves the same way
+/// whether the caller wrote `"0.93.7"` or `"v0.93.7"`; `resolve_tag` then matches the result
+/// against the repo's actual tags (which may or may not be `v`-prefixed themselves) rather than
+/// guessing at the tag's format.
+fn normalize_version_req(version: &str) -> &str {
+    match version.strip_prefix('v') {
+        Some(rest) if Version::parse(rest).is_ok() => rest,
+        _ => version,
+    }
+}
+
+fn default_repo_mapping() -> HashMap<String, (String, String)> {
+    [
+        (ReleaseType::Faucet, "safe_network"),
+        (ReleaseType::Safe, "safe_network"),
+        (ReleaseType::Safenode, "safe_network"),
+        (ReleaseType::SafenodeManager, "sn-node-manager"),
+        (ReleaseType::SafenodeRpcClient, "safe_network"),
+    ]
+    .into_iter()
+    .map(|(release_type, repo)| (release_type.to_string(), ("maidsafe".to_string(), repo.to_string())))
+    .collect()
+}
+
+async fn fetch_tags(client: &reqwest::Client, owner: &str, repo: &str) -> Result<Vec<GithubTag>, Error> {
+    let url = format!("{GITHUB_API_BASE_URL}/repos/{owner}/{repo}/tags");
+    let response = client
+        .get(&url)
+        .header(reqwest::header::USER_AGENT, "sn-releases")
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(Error::ReleaseBinaryNotFound(url));
+    }
+    Ok(response.json().await?)
+}
+
+#[async_trait]
+impl SafeReleaseRepositoryInterface for GithubReleaseRepository {
+    async fn download_release_from_s3(
+        &self,
+        release_type: &ReleaseType,
+        version: &str,
+        platform: &Platform,
+        archive_type: &ArchiveType,
+        download_dir: &Path,
+        progress_callback: &ProgressCallback,
+    ) -> Result<PathBuf, Error> {
+        let (owner, repo) = self.repo(release_type)?;
+        let client = reqwest::Client::new();
+        let tag = Self::resolve_tag(&client, owner, repo, normalize_version_req(version)).await?;
+
+        let asset_filename = Self::asset_filename(release_type, platform, archive_type);
+        let url = format!("https://github.com/{owner}/{repo}/releases/download/{tag}/{asset_filename}");
+        let local_filename = Self::local_archive_filename(release_type, &tag, platform, archive_type);
+        let archive_path = download_dir.join(&local_filename);
+
+        // GitHub Releases doesn't publish a checksum manifest in the `.sha256`-sibling format
+        // the S3 bucket uses, so there is nothing to verify the downloaded bytes against here.
+        download::download_with_resume(&client, &url, &archive_path, progress_callback, &self.retry_config)
+            .await?;
+
+        Ok(archive_path)
+    }
+
+    fn extract_release_archive(&self, archive_path: &Path, extract_dir: &Path) -> Result<PathBuf, Error> {
+        extract_archive(&self.manifest, archive_path, extract_dir)
+    }
+
+    async fn get_latest_version(&self, release_type: &ReleaseType) -> Result<String, Error> {
+        let (owner, repo) = self.repo(release_type)?;
+        let client = reqwest::Client::new();
+        let tag = Self::resolve_tag(&client, owner, repo, "latest").await?;
+        Ok(tag.strip_prefix('v').unwrap_or(&tag).to_string())
+    }
+
+    async fn download_release_from_s3_by_version_req(
+        &self,
+        release_type: &ReleaseType,
+        version_req: &str,
+        platform: &Platform,
+        archive_type: &ArchiveType,
+        download_dir: &Path,
+        progress_callback: &ProgressCallback,
+    ) -> Result<PathBuf, Error> {
+        self.download_release_from_s3(
+            release_type,
+            version_req,
+            platform,
+            archive_type,
+            download_dir,
+            progress_callback,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_filename_has_no_version_component() {
+        let filename = GithubReleaseRepository::asset_filename(&ReleaseType::Safe, &Platform::MacOs, &ArchiveType::TarGz);
+        assert_eq!(filename, "safe-x86_64-apple-darwin.tar.gz");
+    }
+
+    #[test]
+    fn local_archive_filename_is_namespaced_by_tag_but_keeps_the_release_type_prefix() {
+        let filename = GithubReleaseRepository::local_archive_filename(
+            &ReleaseType::SafenodeManager,
+            "v0.1.8",
+            &Platform::LinuxMuslAarch64,
+            &ArchiveType::Zip,
+        );
+        assert_eq!(filename, "safenode-manager-v0.1.8-aarch64-unknown-linux-musl.zip");
+        // entry_for_archive's longest-prefix match relies on this.
+        assert!(filename.starts_with(&ReleaseType::SafenodeManager.to_string()));
+    }
+
+    #[test]
+    fn normalize_version_req_strips_a_valid_v_prefix() {
+        assert_eq!(normalize_version_req("v0.93.7"), "0.93.7");
+    }
+
+    #[test]
+    fn normalize_version_req_leaves_bare_versions_alone() {
+        assert_eq!(normalize_version_req("0.93.7"), "0.93.7");
+    }
+
+    #[test]
+    fn normalize_version_req_leaves_non_version_requests_alone() {
+        assert_eq!(normalize_version_req("latest"), "latest");
+        assert_eq!(normalize_version_req("latest-rc"), "latest-rc");
+        assert_eq!(normalize_version_req("^0.93"), "^0.93");
+    }
+
+    #[test]
+    fn normalize_version_req_does_not_strip_v_from_a_non_semver_string_starting_with_v() {
+        // "v" isn't a valid version on its own, so only a `v` immediately followed by a parseable
+        // semver should be stripped.
+        assert_eq!(normalize_version_req("version-x"), "version-x");
+    }
+
+    #[test]
+    fn default_repo_mapping_covers_every_release_type() {
+        let mapping = default_repo_mapping();
+        for release_type in [
+            ReleaseType::Faucet,
+            ReleaseType::Safe,
+            ReleaseType::Safenode,
+            ReleaseType::SafenodeManager,
+            ReleaseType::SafenodeRpcClient,
+        ] {
+            assert!(mapping.contains_key(&release_type.to_string()));
+        }
+    }
+}
@@ -0,0 +1,451 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+pub mod error;
+mod download;
+mod github;
+mod manifest;
+mod retry;
+mod version;
+
+pub use github::GithubReleaseRepository;
+pub use manifest::{ReleaseManifest, ReleaseManifestEntry};
+pub use retry::RetryConfig;
+
+use crate::error::Error;
+use async_trait::async_trait;
+use flate2::read::GzDecoder;
+use sha2::Digest;
+use std::fmt;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use tar::Archive;
+
+pub type ProgressCallback = dyn Fn(u64, u64) + Send + Sync;
+
+/// The SAFE Network binaries that can be downloaded through this crate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReleaseType {
+    Faucet,
+    Safe,
+    Safenode,
+    SafenodeManager,
+    SafenodeRpcClient,
+}
+
+impl fmt::Display for ReleaseType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ReleaseType::Faucet => "faucet",
+            ReleaseType::Safe => "safe",
+            ReleaseType::Safenode => "safenode",
+            ReleaseType::SafenodeManager => "safenode-manager",
+            ReleaseType::SafenodeRpcClient => "safenode_rpc_client",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The platforms that released binaries are available for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Platform {
+    LinuxMusl,
+    LinuxMuslAarch64,
+    LinuxMuslArm,
+    LinuxMuslArmV7,
+    MacOs,
+    Windows,
+}
+
+impl Platform {
+    fn get_arch_triple(&self) -> &'static str {
+        match self {
+            Platform::LinuxMusl => "x86_64-unknown-linux-musl",
+            Platform::LinuxMuslAarch64 => "aarch64-unknown-linux-musl",
+            Platform::LinuxMuslArm => "arm-unknown-linux-musleabi",
+            Platform::LinuxMuslArmV7 => "armv7-unknown-linux-musleabihf",
+            Platform::MacOs => "x86_64-apple-darwin",
+            Platform::Windows => "x86_64-pc-windows-msvc",
+        }
+    }
+
+    /// Detects the `Platform` of the machine this code is currently running on, so callers don't
+    /// have to hardcode it themselves.
+    ///
+    /// The OS and CPU architecture are resolved at compile time; on Linux, a runtime probe is
+    /// also used to tell the 32-bit ARM hard-float build (`armv7`) apart from the soft-float one,
+    /// since both report `target_arch = "arm"`.
+    pub fn detect() -> Result<Platform, Error> {
+        if cfg!(target_os = "windows") {
+            return Ok(Platform::Windows);
+        }
+        if cfg!(target_os = "macos") {
+            return Ok(Platform::MacOs);
+        }
+        if cfg!(target_os = "linux") {
+            if !is_musl_libc() {
+                return Err(Error::UnsupportedPlatform(
+                    "only musl-based Linux releases are published".to_string(),
+                ));
+            }
+            if cfg!(target_arch = "aarch64") {
+                return Ok(Platform::LinuxMuslAarch64);
+            }
+            if cfg!(target_arch = "arm") {
+                return Ok(if is_armv7_hardfloat() {
+                    Platform::LinuxMuslArmV7
+                } else {
+                    Platform::LinuxMuslArm
+                });
+            }
+            if cfg!(target_arch = "x86_64") {
+                return Ok(Platform::LinuxMusl);
+            }
+        }
+        Err(Error::UnsupportedPlatform(format!(
+            "{}-{}",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        )))
+    }
+}
+
+/// Whether the host's C library is musl rather than glibc, determined by checking which dynamic
+/// loader is present under the usual library directories.
+fn is_musl_libc() -> bool {
+    for dir in ["/lib", "/lib64", "/usr/lib", "/usr/lib64"] {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            // Check the musl prefix before the glibc one: a glibc loader is never named
+            // `ld-musl-*`, but matching in this order keeps the intent explicit.
+            if name.starts_with("ld-musl") {
+                return true;
+            }
+            if name.starts_with("ld-linux") {
+                return false;
+            }
+        }
+    }
+    // Neither loader was found; assume musl since that's what every Linux release in this
+    // crate is built against.
+    true
+}
+
+/// Distinguishes the `armv7` hard-float build from the `armv6` soft-float one by reading the CPU
+/// architecture and feature list out of `/proc/cpuinfo`. This is only reached for
+/// `target_arch = "arm"` builds: [`Platform::detect`] resolves `aarch64` hosts before it gets here.
+fn is_armv7_hardfloat() -> bool {
+    let Ok(cpuinfo) = std::fs::read_to_string("/proc/cpuinfo") else {
+        return false;
+    };
+    parse_armv7_hardfloat(&cpuinfo)
+}
+
+fn parse_armv7_hardfloat(cpuinfo: &str) -> bool {
+    let is_v7 = cpuinfo
+        .lines()
+        .any(|line| line.starts_with("CPU architecture") && line.contains('7'));
+    let has_hardfloat = cpuinfo
+        .lines()
+        .any(|line| line.starts_with("Features") && (line.contains("vfp") || line.contains("neon")));
+    is_v7 && has_hardfloat
+}
+
+/// The archive formats that released binaries are packaged in.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ArchiveType {
+    TarGz,
+    Zip,
+}
+
+impl ArchiveType {
+    fn extension(&self) -> &'static str {
+        match self {
+            ArchiveType::TarGz => "tar.gz",
+            ArchiveType::Zip => "zip",
+        }
+    }
+}
+
+#[async_trait]
+pub trait SafeReleaseRepositoryInterface {
+    async fn download_release_from_s3(
+        &self,
+        release_type: &ReleaseType,
+        version: &str,
+        platform: &Platform,
+        archive_type: &ArchiveType,
+        download_dir: &Path,
+        progress_callback: &ProgressCallback,
+    ) -> Result<PathBuf, Error>;
+
+    fn extract_release_archive(&self, archive_path: &Path, extract_dir: &Path) -> Result<PathBuf, Error>;
+
+    /// Convenience wrapper around [`Platform::detect`] so callers working through the trait
+    /// object don't need to import `Platform` just to resolve the host platform.
+    fn detect_current(&self) -> Result<Platform, Error> {
+        Platform::detect()
+    }
+
+    /// Returns the highest published stable version for a release type. Pre-release versions
+    /// (`rc`, `nightly`, ...) are never considered here; use [`Self::download_release_from_s3_by_version_req`]
+    /// with `"latest-rc"` or `"latest-nightly"` to opt into those channels.
+    async fn get_latest_version(&self, release_type: &ReleaseType) -> Result<String, Error>;
+
+    /// A version of [`Self::download_release_from_s3`] that resolves `version_req` against the
+    /// published versions before downloading. See [`version::resolve`] for the accepted forms of
+    /// `version_req`.
+    async fn download_release_from_s3_by_version_req(
+        &self,
+        release_type: &ReleaseType,
+        version_req: &str,
+        platform: &Platform,
+        archive_type: &ArchiveType,
+        download_dir: &Path,
+        progress_callback: &ProgressCallback,
+    ) -> Result<PathBuf, Error>;
+}
+
+impl dyn SafeReleaseRepositoryInterface {
+    /// Returns a release repository configured to verify the checksum of every downloaded
+    /// archive against the `.sha256` manifest published alongside it, driven by this crate's
+    /// built-in manifest of SAFE binaries.
+    pub fn default_config() -> Box<dyn SafeReleaseRepositoryInterface> {
+        Box::new(SafeReleaseRepository {
+            verify_checksum: true,
+            manifest: ReleaseManifest::default_manifest(),
+            retry_config: RetryConfig::default(),
+        })
+    }
+
+    /// Returns a release repository that only checks for the availability of a release and does
+    /// not verify its checksum after download.
+    pub fn config_without_checksum_verification() -> Box<dyn SafeReleaseRepositoryInterface> {
+        Box::new(SafeReleaseRepository {
+            verify_checksum: false,
+            manifest: ReleaseManifest::default_manifest(),
+            retry_config: RetryConfig::default(),
+        })
+    }
+
+    /// Returns a release repository driven entirely by the supplied manifest, letting callers
+    /// register release types this crate doesn't know about without a new crate release.
+    pub fn from_manifest(manifest: ReleaseManifest) -> Box<dyn SafeReleaseRepositoryInterface> {
+        Box::new(SafeReleaseRepository {
+            verify_checksum: true,
+            manifest,
+            retry_config: RetryConfig::default(),
+        })
+    }
+
+    /// Fetches a manifest from `url` and returns a release repository driven by it.
+    pub async fn from_manifest_url(url: &str) -> Result<Box<dyn SafeReleaseRepositoryInterface>, Error> {
+        let manifest = ReleaseManifest::fetch(url).await?;
+        Ok(<dyn SafeReleaseRepositoryInterface>::from_manifest(manifest))
+    }
+
+    /// Returns the default release repository with a custom retry policy for resumable
+    /// downloads, instead of the built-in default of 3 attempts with a 500ms base delay.
+    pub fn default_config_with_retry_config(retry_config: RetryConfig) -> Box<dyn SafeReleaseRepositoryInterface> {
+        Box::new(SafeReleaseRepository {
+            verify_checksum: true,
+            manifest: ReleaseManifest::default_manifest(),
+            retry_config,
+        })
+    }
+}
+
+pub struct SafeReleaseRepository {
+    verify_checksum: bool,
+    manifest: ReleaseManifest,
+    retry_config: RetryConfig,
+}
+
+#[async_trait]
+impl SafeReleaseRepositoryInterface for SafeReleaseRepository {
+    async fn download_release_from_s3(
+        &self,
+        release_type: &ReleaseType,
+        version: &str,
+        platform: &Platform,
+        archive_type: &ArchiveType,
+        download_dir: &Path,
+        progress_callback: &ProgressCallback,
+    ) -> Result<PathBuf, Error> {
+        let entry = self.manifest.entry(release_type)?;
+        let archive_filename = manifest::render_archive_filename(entry, version, platform, archive_type);
+        let url = format!("{}/{archive_filename}", entry.base_url);
+        let archive_path = download_dir.join(&archive_filename);
+
+        let client = reqwest::Client::new();
+
+        // Download the archive first so a missing release is reported via the archive's own
+        // URL (as `download_with_resume`'s `ReleaseBinaryNotFound` does), rather than via the
+        // `.sha256` sibling's URL if the checksum manifest were fetched first.
+        let hasher = download::download_with_resume(
+            &client,
+            &url,
+            &archive_path,
+            progress_callback,
+            &self.retry_config,
+        )
+        .await?;
+
+        if self.verify_checksum {
+            let expected = fetch_expected_checksum(&client, &url).await?;
+            let actual = hex::encode(hasher.finalize());
+            if actual != expected {
+                return Err(Error::ChecksumMismatch { expected, actual });
+            }
+        }
+
+        Ok(archive_path)
+    }
+
+    fn extract_release_archive(&self, archive_path: &Path, extract_dir: &Path) -> Result<PathBuf, Error> {
+        extract_archive(&self.manifest, archive_path, extract_dir)
+    }
+
+    async fn get_latest_version(&self, release_type: &ReleaseType) -> Result<String, Error> {
+        let base_url = &self.manifest.entry(release_type)?.base_url;
+        let versions = version::list_versions(release_type, base_url).await?;
+        version::resolve("latest", &versions)
+    }
+
+    async fn download_release_from_s3_by_version_req(
+        &self,
+        release_type: &ReleaseType,
+        version_req: &str,
+        platform: &Platform,
+        archive_type: &ArchiveType,
+        download_dir: &Path,
+        progress_callback: &ProgressCallback,
+    ) -> Result<PathBuf, Error> {
+        let base_url = self.manifest.entry(release_type)?.base_url.clone();
+        let versions = version::list_versions(release_type, &base_url).await?;
+        let version = version::resolve(version_req, &versions)?;
+        self.download_release_from_s3(
+            release_type,
+            &version,
+            platform,
+            archive_type,
+            download_dir,
+            progress_callback,
+        )
+        .await
+    }
+}
+
+/// Unpacks a downloaded archive and returns the path to the binary inside it, looking the
+/// archive's release type up in `manifest` to work out the extracted binary name. Shared between
+/// every [`SafeReleaseRepositoryInterface`] backend, since they all lay archives out the same way
+/// once downloaded.
+pub(crate) fn extract_archive(
+    manifest: &ReleaseManifest,
+    archive_path: &Path,
+    extract_dir: &Path,
+) -> Result<PathBuf, Error> {
+    let filename = archive_path
+        .file_name()
+        .and_then(|f| f.to_str())
+        .unwrap_or_default();
+
+    if filename.ends_with(".tar.gz") {
+        let file = File::open(archive_path)?;
+        let tar = GzDecoder::new(file);
+        let mut archive = Archive::new(tar);
+        archive.unpack(extract_dir)?;
+    } else {
+        let file = File::open(archive_path)?;
+        let mut archive = zip::ZipArchive::new(file).map_err(std::io::Error::from)?;
+        archive.extract(extract_dir).map_err(std::io::Error::from)?;
+    }
+
+    let entry = manifest.entry_for_archive(filename)?;
+    let binary_name = manifest::render_binary_name(entry, filename.contains("windows"));
+    Ok(extract_dir.join(binary_name))
+}
+
+/// Fetches the expected SHA-256 checksum from the `.sha256` sibling object published next to the
+/// archive, e.g. `safe-0.83.51-x86_64-unknown-linux-musl.tar.gz.sha256`.
+async fn fetch_expected_checksum(client: &reqwest::Client, archive_url: &str) -> Result<String, Error> {
+    let checksum_url = format!("{archive_url}.sha256");
+    let response = client.get(&checksum_url).send().await?;
+    if !response.status().is_success() {
+        return Err(Error::ReleaseBinaryNotFound(checksum_url));
+    }
+    let body = response.text().await?;
+    let digest = body
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    Ok(digest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARMV7_HARDFLOAT_CPUINFO: &str = "\
+processor\t: 0
+model name\t: ARMv7 Processor rev 3 (v7l)
+Features\t: half thumb fastmult vfp edsp neon vfpv3 tls
+CPU architecture: 7
+";
+
+    const ARMV6_SOFTFLOAT_CPUINFO: &str = "\
+processor\t: 0
+model name\t: ARMv6-compatible processor rev 7 (v6l)
+Features\t: swp half thumb fastmult vfp edsp java
+CPU architecture: 6
+";
+
+    const AARCH64_CPUINFO: &str = "\
+processor\t: 0
+BogoMIPS\t: 108.00
+Features\t: fp asimd evtstrm aes pmull sha1 sha2 crc32
+CPU architecture: 8
+";
+
+    #[test]
+    fn parse_armv7_hardfloat_detects_v7_with_vfp_or_neon() {
+        assert!(parse_armv7_hardfloat(ARMV7_HARDFLOAT_CPUINFO));
+    }
+
+    #[test]
+    fn parse_armv7_hardfloat_rejects_v6_softfloat() {
+        // `CPU architecture: 6` doesn't contain '7', so this must not match even though the
+        // `Features` line happens to list `vfp`.
+        assert!(!parse_armv7_hardfloat(ARMV6_SOFTFLOAT_CPUINFO));
+    }
+
+    #[test]
+    fn parse_armv7_hardfloat_rejects_aarch64() {
+        assert!(!parse_armv7_hardfloat(AARCH64_CPUINFO));
+    }
+
+    #[test]
+    fn parse_armv7_hardfloat_rejects_empty_input() {
+        assert!(!parse_armv7_hardfloat(""));
+    }
+
+    #[test]
+    fn arch_triples_disambiguate_armv7_from_aarch64_and_plain_arm() {
+        // The arm/armv7/aarch64 triples share prefixes ("arm..." vs "aarch64"), so `Platform`
+        // must map each variant to a string that doesn't collide with the others.
+        assert_eq!(Platform::LinuxMuslAarch64.get_arch_triple(), "aarch64-unknown-linux-musl");
+        assert_eq!(Platform::LinuxMuslArmV7.get_arch_triple(), "armv7-unknown-linux-musleabihf");
+        assert_eq!(Platform::LinuxMuslArm.get_arch_triple(), "arm-unknown-linux-musleabi");
+    }
+}
+
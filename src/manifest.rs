@@ -0,0 +1,136 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::error::Error;
+use crate::{ArchiveType, Platform, ReleaseType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const DEFAULT_MANIFEST_JSON: &str = include_str!("../manifests/default.json");
+
+/// Describes, per release type, where to fetch its archives from and how to lay them out. This
+/// lets a downstream user register a new SAFE binary by supplying their own manifest instead of
+/// having to patch this crate's source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseManifest {
+    pub releases: HashMap<String, ReleaseManifestEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseManifestEntry {
+    pub base_url: String,
+    /// Archive filename template, e.g. `"safe-{version}-{arch_triple}.{ext}"`. The
+    /// `{version}`, `{arch_triple}` and `{ext}` placeholders are substituted at download time.
+    pub archive_template: String,
+    /// The name of the binary once the archive is extracted, without the Windows `.exe` suffix.
+    pub binary_name: String,
+}
+
+impl ReleaseManifest {
+    /// The manifest describing the SAFE binaries this crate has always known how to fetch.
+    pub fn default_manifest() -> Self {
+        serde_json::from_str(DEFAULT_MANIFEST_JSON)
+            .expect("the embedded default manifest is valid JSON")
+    }
+
+    /// Fetches and parses a manifest from a URL, for callers who want to point at a manifest
+    /// that lists additional release types.
+    pub async fn fetch(url: &str) -> Result<Self, Error> {
+        let response = reqwest::get(url).await?;
+        if !response.status().is_success() {
+            return Err(Error::ReleaseBinaryNotFound(url.to_string()));
+        }
+        let manifest = response.json().await?;
+        Ok(manifest)
+    }
+
+    pub(crate) fn entry(&self, release_type: &ReleaseType) -> Result<&ReleaseManifestEntry, Error> {
+        self.releases
+            .get(&release_type.to_string())
+            .ok_or_else(|| Error::ReleaseBinaryNotFound(release_type.to_string()))
+    }
+
+    /// Finds the manifest entry whose name is the longest prefix of `archive_filename`, which is
+    /// how an archive on disk is mapped back to its release type during extraction. The longest
+    /// match is needed because e.g. `safenode-manager` is itself prefixed by `safenode`.
+    pub(crate) fn entry_for_archive(&self, archive_filename: &str) -> Result<&ReleaseManifestEntry, Error> {
+        self.releases
+            .iter()
+            .filter(|(name, _)| archive_filename.starts_with(name.as_str()))
+            .max_by_key(|(name, _)| name.len())
+            .map(|(_, entry)| entry)
+            .ok_or_else(|| Error::ReleaseBinaryNotFound(archive_filename.to_string()))
+    }
+}
+
+pub(crate) fn render_archive_filename(
+    entry: &ReleaseManifestEntry,
+    version: &str,
+    platform: &Platform,
+    archive_type: &ArchiveType,
+) -> String {
+    entry
+        .archive_template
+        .replace("{version}", version)
+        .replace("{arch_triple}", platform.get_arch_triple())
+        .replace("{ext}", archive_type.extension())
+}
+
+pub(crate) fn render_binary_name(entry: &ReleaseManifestEntry, is_windows: bool) -> String {
+    if is_windows {
+        format!("{}.exe", entry.binary_name)
+    } else {
+        entry.binary_name.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest_with(names: &[&str]) -> ReleaseManifest {
+        let releases = names
+            .iter()
+            .map(|name| {
+                (
+                    name.to_string(),
+                    ReleaseManifestEntry {
+                        base_url: "https://example.com".to_string(),
+                        archive_template: "{version}-{arch_triple}.{ext}".to_string(),
+                        binary_name: name.to_string(),
+                    },
+                )
+            })
+            .collect();
+        ReleaseManifest { releases }
+    }
+
+    #[test]
+    fn entry_for_archive_picks_the_longest_matching_prefix() {
+        let manifest = manifest_with(&["safenode", "safenode-manager"]);
+        let entry = manifest
+            .entry_for_archive("safenode-manager-0.1.0-x86_64-unknown-linux-musl.tar.gz")
+            .unwrap();
+        assert_eq!(entry.binary_name, "safenode-manager");
+    }
+
+    #[test]
+    fn entry_for_archive_falls_back_to_the_shorter_name_when_it_alone_matches() {
+        let manifest = manifest_with(&["safenode", "safenode-manager"]);
+        let entry = manifest
+            .entry_for_archive("safenode-0.1.0-x86_64-unknown-linux-musl.tar.gz")
+            .unwrap();
+        assert_eq!(entry.binary_name, "safenode");
+    }
+
+    #[test]
+    fn entry_for_archive_errors_when_nothing_matches() {
+        let manifest = manifest_with(&["safenode"]);
+        assert!(manifest.entry_for_archive("faucet-0.1.0.tar.gz").is_err());
+    }
+}
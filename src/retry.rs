@@ -0,0 +1,52 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use std::time::Duration;
+
+/// Controls how a download retries after a transient failure (a connection drop, a timeout, or
+/// a 5xx response). The `n`th retry waits `base_delay * 2^(n - 1)` before trying again, so the
+/// first retry waits `base_delay` and each later one waits twice as long as the one before.
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// The delay to wait before the `attempt`th retry (1-indexed: `attempt` 1 is the first
+    /// retry, after the initial try already failed once).
+    pub(crate) fn backoff_delay(&self, attempt: u32) -> Duration {
+        self.base_delay * 2u32.pow(attempt - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_doubles_each_attempt() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+        };
+        assert_eq!(config.backoff_delay(1), Duration::from_millis(100));
+        assert_eq!(config.backoff_delay(2), Duration::from_millis(200));
+        assert_eq!(config.backoff_delay(3), Duration::from_millis(400));
+        assert_eq!(config.backoff_delay(4), Duration::from_millis(800));
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2024 MaidSafe.net limited.
+//
+// This SAFE Network Software is licensed to you under The General Public License (GPL), version 3.
+// Unless required by applicable law or agreed to in writing, the SAFE Network Software distributed
+// under the GPL Licence is distributed on an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied. Please review the Licences for the specific language governing
+// permissions and limitations relating to use of the SAFE Network Software.
+
+use crate::error::Error;
+use crate::ReleaseType;
+use semver::{Version, VersionReq};
+
+/// Fetches the list of published versions for a release type from the
+/// `<base_url>/<release_type>/versions.json` index object.
+pub(crate) async fn list_versions(release_type: &ReleaseType, base_url: &str) -> Result<Vec<Version>, Error> {
+    let url = format!("{base_url}/{release_type}/versions.json");
+    let response = reqwest::get(&url).await?;
+    if !response.status().is_success() {
+        return Err(Error::ReleaseBinaryNotFound(url));
+    }
+    let raw_versions: Vec<String> = response.json().await?;
+    let versions = raw_versions
+        .iter()
+        .filter_map(|v| Version::parse(v).ok())
+        .collect();
+    Ok(versions)
+}
+
+/// Resolves a caller-supplied version request against the published versions for a release
+/// type. `version_req` may be:
+/// * `"latest"` – the highest stable version (pre-releases are never considered)
+/// * `"latest-rc"` / `"latest-nightly"` – the highest version on that pre-release channel
+/// * a semver range, e.g. `"^0.93"` or `"0.83.x"` – matched against stable versions only
+/// * an explicit version, e.g. `"0.93.7"` or `"0.94.0-rc.1"` – returned as-is; this is the only
+///   way to select a specific pre-release build, since ranges never match one implicitly
+pub(crate) fn resolve(version_req: &str, versions: &[Version]) -> Result<String, Error> {
+    match version_req {
+        "latest" => highest_stable(versions),
+        "latest-rc" => highest_on_channel(versions, "rc"),
+        "latest-nightly" => highest_on_channel(versions, "nightly"),
+        _ => {
+            if let Ok(exact) = Version::parse(version_req) {
+                return Ok(exact.to_string());
+            }
+            let req = VersionReq::parse(version_req)
+                .map_err(|e| Error::InvalidVersionRequest(format!("'{version_req}': {e}")))?;
+            versions
+                .iter()
+                .filter(|v| v.pre.is_empty() && req.matches(v))
+                .max()
+                .map(|v| v.to_string())
+                .ok_or_else(|| Error::ReleaseBinaryNotFound(version_req.to_string()))
+        }
+    }
+}
+
+fn highest_stable(versions: &[Version]) -> Result<String, Error> {
+    versions
+        .iter()
+        .filter(|v| v.pre.is_empty())
+        .max()
+        .map(|v| v.to_string())
+        .ok_or_else(|| Error::ReleaseBinaryNotFound("no stable version available".to_string()))
+}
+
+fn highest_on_channel(versions: &[Version], channel: &str) -> Result<String, Error> {
+    versions
+        .iter()
+        .filter(|v| v.pre.as_str().starts_with(channel))
+        .max()
+        .map(|v| v.to_string())
+        .ok_or_else(|| Error::ReleaseBinaryNotFound(format!("no '{channel}' version available")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions(raw: &[&str]) -> Vec<Version> {
+        raw.iter().map(|v| Version::parse(v).unwrap()).collect()
+    }
+
+    #[test]
+    fn resolve_latest_ignores_prereleases() {
+        let versions = versions(&["0.93.6", "0.93.7", "0.94.0-rc.1"]);
+        assert_eq!(resolve("latest", &versions).unwrap(), "0.93.7");
+    }
+
+    #[test]
+    fn resolve_latest_errors_when_only_prereleases_are_published() {
+        let versions = versions(&["0.94.0-rc.1", "0.94.0-nightly.20240501"]);
+        assert!(resolve("latest", &versions).is_err());
+    }
+
+    #[test]
+    fn resolve_latest_rc_picks_highest_rc_and_ignores_nightly() {
+        let versions = versions(&["0.93.7", "0.94.0-rc.1", "0.94.0-rc.2", "0.94.0-nightly.20240501"]);
+        assert_eq!(resolve("latest-rc", &versions).unwrap(), "0.94.0-rc.2");
+    }
+
+    #[test]
+    fn resolve_latest_nightly_picks_highest_nightly() {
+        let versions = versions(&["0.94.0-nightly.20240501", "0.94.0-nightly.20240601"]);
+        assert_eq!(resolve("latest-nightly", &versions).unwrap(), "0.94.0-nightly.20240601");
+    }
+
+    #[test]
+    fn resolve_semver_range_excludes_prereleases() {
+        let versions = versions(&["0.93.5", "0.93.7", "0.94.0-rc.1"]);
+        assert_eq!(resolve("^0.93", &versions).unwrap(), "0.93.7");
+    }
+
+    #[test]
+    fn resolve_exact_prerelease_bypasses_the_published_versions_list() {
+        // An exact version is returned as-is even if it isn't in `versions` at all; this is the
+        // only way to select a specific pre-release build.
+        assert_eq!(resolve("0.94.0-rc.1", &[]).unwrap(), "0.94.0-rc.1");
+    }
+
+    #[test]
+    fn resolve_invalid_range_is_an_error() {
+        assert!(resolve("not-a-version-request", &versions(&["0.93.7"])).is_err());
+    }
+}